@@ -1,16 +1,404 @@
 use std::{
-  net::TcpListener,
+  io::{BufRead, BufReader},
   path::PathBuf,
-  process::{Child, Command},
-  sync::Mutex,
+  process::{Child, Command, Stdio},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+  },
+  time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
-use tauri::{Manager, RunEvent};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, RunEvent};
 
-struct BridgeState(Mutex<Option<Child>>);
+/// A single line of bridge stdout/stderr, forwarded to the frontend as a
+/// `bridge-log` event so users can see capture/tracking diagnostics without
+/// attaching a debugger.
+#[derive(Clone, Serialize)]
+struct BridgeLog {
+  level: String,
+  line: String,
+  ts: u64,
+}
+
+/// How long to wait for the bridge to exit on its own after asking it to
+/// shut down cleanly before we fall back to `Child::kill`.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+
+/// How long we'll poll a single candidate port for the bridge to come up
+/// before treating that attempt as failed (`BridgeProbe::TimedOut`).
+const READY_TIMEOUT: Duration = Duration::from_secs(20);
+const READY_BACKOFF_START: Duration = Duration::from_millis(50);
+const READY_BACKOFF_CAP: Duration = Duration::from_secs(1);
+
+/// Lowest Python `(major, minor)` MotionView's bridge is tested against.
+const MIN_PYTHON_VERSION: (u32, u32) = (3, 10);
+
+/// Why the bridge preflight check failed, kept around long enough to build a
+/// user-facing explanation instead of panicking with no window on screen.
+enum PreflightError {
+  NoProjectRoot,
+  NoInterpreter,
+  VersionTooOld { found: String, min: (u32, u32) },
+  BridgeMissing(PathBuf),
+}
+
+impl PreflightError {
+  fn message(&self) -> String {
+    match self {
+      PreflightError::NoProjectRoot => {
+        "MotionView couldn't locate its project root (no package.json/pnpm-lock.yaml \
+         found in any parent directory).\n\n\
+         Reinstall MotionView and relaunch."
+          .to_string()
+      }
+      PreflightError::NoInterpreter => {
+        "MotionView couldn't find a Python interpreter.\n\n\
+         Install Python 3.10 or newer and make sure it's on your PATH \
+         (or create a .venv in the project root), then relaunch MotionView."
+          .to_string()
+      }
+      PreflightError::VersionTooOld { found, min } => format!(
+        "MotionView needs Python {}.{}+ but found {found}.\n\n\
+         Install a newer Python (or point .venv at one) and relaunch MotionView.",
+        min.0, min.1
+      ),
+      PreflightError::BridgeMissing(path) => format!(
+        "MotionView's bridge script is missing:\n{}\n\n\
+         Reinstall MotionView or restore this file and relaunch.",
+        path.display()
+      ),
+    }
+  }
+}
+
+/// Candidate Python interpreters to try, in preference order: the project's
+/// own `.venv` first, then whatever the platform's system Python is called.
+fn python_candidates(root: &PathBuf) -> Vec<PathBuf> {
+  let mut candidates = Vec::new();
+  if let Some(venv) = venv_python(root) {
+    candidates.push(venv);
+  }
+  if cfg!(target_os = "windows") {
+    candidates.push(PathBuf::from("py"));
+  } else {
+    candidates.push(PathBuf::from("python3"));
+  }
+  candidates
+}
+
+/// Parse the `major.minor` out of a `python --version` style string, e.g.
+/// `"Python 3.11.4"` -> `(3, 11)`.
+fn parse_python_version(text: &str) -> Option<(u32, u32)> {
+  let version = text.trim().strip_prefix("Python ")?.trim();
+  let mut parts = version.split('.');
+  let major = parts.next()?.parse().ok()?;
+  let minor = parts.next()?.parse().ok()?;
+  Some((major, minor))
+}
+
+/// Resolve a usable Python interpreter and confirm it meets
+/// `MIN_PYTHON_VERSION`, and that `bridge.py` actually exists, before we ever
+/// try to spawn the bridge. Surfacing a clear error here beats an `expect`
+/// panic with no window on screen.
+fn preflight(root: &PathBuf) -> Result<PathBuf, PreflightError> {
+  let script = root.join("src").join("bridge.py");
+  if !script.exists() {
+    return Err(PreflightError::BridgeMissing(script));
+  }
+
+  // A stale .venv (created once against an old Python, never rebuilt) is
+  // common; don't let it block a perfectly good system interpreter further
+  // down the candidate list. Only report `VersionTooOld` if nothing in the
+  // whole list passes.
+  let mut stale: Option<PreflightError> = None;
+
+  for candidate in python_candidates(root) {
+    let mut cmd = Command::new(&candidate);
+    if candidate.file_stem().and_then(|s| s.to_str()) == Some("py") {
+      cmd.arg("-3");
+    }
+    let Ok(output) = cmd.arg("--version").output() else {
+      continue;
+    };
+    // Python < 3.4 prints to stderr; newer versions print to stdout.
+    let text = String::from_utf8_lossy(&output.stdout).into_owned();
+    let text = if text.trim().is_empty() {
+      String::from_utf8_lossy(&output.stderr).into_owned()
+    } else {
+      text
+    };
+
+    let Some(version) = parse_python_version(&text) else {
+      continue;
+    };
+    if version < MIN_PYTHON_VERSION {
+      stale.get_or_insert(PreflightError::VersionTooOld {
+        found: text.trim().to_string(),
+        min: MIN_PYTHON_VERSION,
+      });
+      continue;
+    }
+    return Ok(candidate);
+  }
+
+  Err(stale.unwrap_or(PreflightError::NoInterpreter))
+}
+
+/// Encode a Rust string as a JS string-literal we can safely splice into an
+/// `eval()`'d script. `serde_json`'s string escaping is complete (quotes,
+/// backslashes, control characters, ...) where hand-rolled `.replace()`
+/// chains tend to miss a case on crafted input.
+fn js_string(value: &str) -> String {
+  serde_json::to_string(value).expect("serializing a string is infallible")
+}
+
+/// Replace the main window's contents with a plain explanation of why the
+/// bridge couldn't start, instead of leaving the user staring at a blank or
+/// crashed app.
+fn show_error(app: &tauri::App, message: &str) {
+  let Some(win) = app.get_webview_window("main") else {
+    return;
+  };
+  let message = js_string(message);
+  let _ = win.eval(&format!(
+    "(function(){{\
+       var pre = document.createElement('pre');\
+       pre.style.cssText = 'font:14px monospace;padding:2rem;white-space:pre-wrap';\
+       pre.textContent = {message};\
+       document.body.innerHTML = '';\
+       document.body.appendChild(pre);\
+     }})();"
+  ));
+}
+
+fn show_preflight_error(app: &tauri::App, err: &PreflightError) {
+  show_error(app, &err.message());
+}
+
+/// Default ephemeral port range to pick bridge ports from.
+const DEFAULT_PORT_RANGE: (u16, u16) = (49152, 65535);
+/// How many candidate ports we'll try before giving up and reporting
+/// `bridge-failed`.
+const MAX_PORT_ATTEMPTS: usize = 10;
+
+/// How often the supervisor checks whether the bridge child is still alive.
+const SUPERVISOR_POLL: Duration = Duration::from_millis(500);
+/// At most this many restarts within `RESTART_WINDOW` before giving up and
+/// emitting `bridge-fatal` instead of respawning again.
+const MAX_RESTARTS: usize = 5;
+const RESTART_WINDOW: Duration = Duration::from_secs(60);
+/// Delay before the first respawn; doubles with each consecutive restart
+/// (capped at `RESTART_BACKOFF_CAP`) so a bridge that dies immediately on
+/// every launch doesn't get relaunched on essentially every `SUPERVISOR_POLL`
+/// tick while it burns through `MAX_RESTARTS`.
+const RESTART_BACKOFF_START: Duration = Duration::from_millis(500);
+const RESTART_BACKOFF_CAP: Duration = Duration::from_secs(10);
+
+struct BridgeHandle {
+  child: Child,
+  port: u16,
+}
+
+struct BridgeState {
+  handle: Mutex<Option<BridgeHandle>>,
+  /// Set just before we deliberately stop the bridge (app exit) so the
+  /// supervisor can tell an intentional stop from a crash.
+  shutting_down: AtomicBool,
+}
+
+impl BridgeState {
+  fn new() -> Self {
+    Self {
+      handle: Mutex::new(None),
+      shutting_down: AtomicBool::new(false),
+    }
+  }
+}
+
+/// Ask the bridge to stop on its own, then poll for exit before killing it.
+///
+/// Tries a clean `/shutdown` request first (the bridge's own HTTP server) and
+/// gives it half of `SHUTDOWN_GRACE` to land. Most plain Python scripts don't
+/// trap SIGTERM, so firing it alongside the HTTP request would tear the
+/// process down before it can flush in-flight writes — only escalate to the
+/// signal, and then to `kill()`, if the softer step didn't work.
+fn shutdown_bridge(mut handle: BridgeHandle) {
+  request_shutdown(handle.port);
+  if wait_for_exit(&mut handle.child, SHUTDOWN_GRACE / 2) {
+    return;
+  }
+
+  signal_shutdown(&handle.child);
+  if wait_for_exit(&mut handle.child, SHUTDOWN_GRACE / 2) {
+    return;
+  }
+
+  let _ = handle.child.kill();
+  let _ = handle.child.wait();
+}
+
+/// Poll `child` for exit until it's gone or `timeout` elapses.
+fn wait_for_exit(child: &mut Child, timeout: Duration) -> bool {
+  let deadline = Instant::now() + timeout;
+  loop {
+    match child.try_wait() {
+      Ok(Some(_)) => return true,
+      Ok(None) => {
+        if Instant::now() >= deadline {
+          return false;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+      }
+      Err(_) => return false,
+    }
+  }
+}
+
+/// Best-effort POST to the bridge's `/shutdown` route; failures are fine,
+/// since the grace-period poll below still catches an unresponsive bridge.
+fn request_shutdown(port: u16) {
+  use std::io::Write;
+  if let Ok(mut stream) = std::net::TcpStream::connect(("127.0.0.1", port)) {
+    let _ = stream.write_all(
+      format!(
+        "POST /shutdown HTTP/1.1\r\nHost: 127.0.0.1:{port}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+      )
+      .as_bytes(),
+    );
+  }
+}
+
+#[cfg(unix)]
+fn signal_shutdown(child: &Child) {
+  // Talk to the kernel directly instead of shelling out to the `kill`
+  // binary, so this doesn't depend on it being on PATH.
+  //
+  // SAFETY: `child.id()` is the pid of a child process we spawned and still
+  // hold a handle to, so it's a valid target for SIGTERM.
+  unsafe {
+    libc::kill(child.id() as libc::pid_t, libc::SIGTERM);
+  }
+}
+
+#[cfg(windows)]
+fn signal_shutdown(child: &Child) {
+  // Best-effort: taskkill without /F (no forced termination), so the bridge
+  // gets a chance to handle the request cleanly. No console break event is
+  // sent to the process group.
+  let _ = Command::new("taskkill")
+    .args(["/PID", &child.id().to_string()])
+    .output();
+}
+
+/// Outcome of polling a candidate port while the bridge is (hopefully)
+/// coming up.
+enum BridgeProbe {
+  Ready,
+  /// The child exited before it ever answered, most likely because the port
+  /// we picked was already taken and its bind failed.
+  Exited,
+  /// Nothing answered within `READY_TIMEOUT`. `bridge.py` is expected to
+  /// bind its port (or give up and exit) within that window; if it instead
+  /// logs-and-keeps-running on a failed bind, this is how that shows up, so
+  /// we treat it the same as `Exited` for retry purposes.
+  TimedOut,
+}
+
+/// Poll `127.0.0.1:{port}` with exponential backoff until it accepts a
+/// connection, the bridge child exits (bind failure), or `READY_TIMEOUT`
+/// elapses.
+fn probe_bridge(app_handle: &AppHandle, port: u16) -> BridgeProbe {
+  let deadline = Instant::now() + READY_TIMEOUT;
+  let mut backoff = READY_BACKOFF_START;
+
+  while Instant::now() < deadline {
+    if std::net::TcpStream::connect(("127.0.0.1", port)).is_ok() {
+      return BridgeProbe::Ready;
+    }
+
+    let state = app_handle.state::<BridgeState>();
+    let exited = match state.handle.lock().unwrap().as_mut() {
+      Some(handle) => matches!(handle.child.try_wait(), Ok(Some(_))),
+      None => true,
+    };
+    if exited {
+      return BridgeProbe::Exited;
+    }
+
+    std::thread::sleep(backoff);
+    backoff = (backoff * 2).min(READY_BACKOFF_CAP);
+  }
+  BridgeProbe::TimedOut
+}
+
+/// Draw a candidate port from `range`, inclusive on both ends. We don't
+/// pre-bind and drop a listener to "reserve" it (that's exactly the race
+/// that let another process grab it first) — instead the caller spawns the
+/// bridge straight onto this port and retries with a new candidate if the
+/// bind fails.
+fn pick_candidate_port(range: (u16, u16)) -> u16 {
+  let (min, max) = range;
+  let span = u64::from(max - min) + 1;
+  let seed = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_nanos() as u64)
+    .unwrap_or(0)
+    ^ (std::process::id() as u64).wrapping_mul(0x9E3779B97F4A7C15);
+  min + (seed % span) as u16
+}
+
+/// Parse a `"min-max"` port range string.
+fn parse_port_range(s: &str) -> Option<(u16, u16)> {
+  let (min, max) = s.trim().split_once('-')?;
+  let min: u16 = min.trim().parse().ok()?;
+  let max: u16 = max.trim().parse().ok()?;
+  (min <= max).then_some((min, max))
+}
+
+/// The ephemeral port range MotionView picks bridge ports from. Configurable
+/// via `--port-range min-max` or the `MOTIONVIEW_PORT_RANGE` env var, so
+/// users on locked-down networks can constrain which ports get used.
+fn port_range() -> (u16, u16) {
+  let args: Vec<String> = std::env::args().collect();
+  let from_flag = args
+    .windows(2)
+    .find(|w| w[0] == "--port-range")
+    .and_then(|w| parse_port_range(&w[1]));
+
+  from_flag
+    .or_else(|| std::env::var("MOTIONVIEW_PORT_RANGE").ok().and_then(|v| parse_port_range(&v)))
+    .unwrap_or(DEFAULT_PORT_RANGE)
+}
 
-fn pick_free_port() -> u16 {
-  let l = TcpListener::bind(("127.0.0.1", 0)).expect("bind 127.0.0.1:0");
-  l.local_addr().unwrap().port()
+/// Check for `--bridge-url <url>` or `MOTIONVIEW_BRIDGE_URL`, letting users
+/// who run the capture backend on a separate machine point MotionView at it
+/// instead of spawning a local Python process.
+fn bridge_url_override() -> Option<String> {
+  let args: Vec<String> = std::env::args().collect();
+  args
+    .windows(2)
+    .find(|w| w[0] == "--bridge-url")
+    .map(|w| w[1].clone())
+    .or_else(|| std::env::var("MOTIONVIEW_BRIDGE_URL").ok())
+}
+
+/// Minimal sanity check on a user-supplied bridge URL: must be `http(s)://`
+/// with a non-empty host. We don't pull in a URL-parsing crate for this one
+/// check.
+fn validate_bridge_url(url: &str) -> Result<String, String> {
+  let url = url.trim();
+  let rest = url
+    .strip_prefix("http://")
+    .or_else(|| url.strip_prefix("https://"))
+    .ok_or_else(|| format!("bridge URL must start with http:// or https://: {url}"))?;
+
+  let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+  if host.is_empty() {
+    return Err(format!("bridge URL is missing a host: {url}"));
+  }
+
+  Ok(url.trim_end_matches('/').to_string())
 }
 
 fn venv_python(root: &PathBuf) -> Option<PathBuf> {
@@ -22,65 +410,408 @@ fn venv_python(root: &PathBuf) -> Option<PathBuf> {
   p.exists().then_some(p)
 }
 
-fn find_project_root() -> PathBuf {
-  let mut dir = std::env::current_dir().expect("current_dir");
+/// Walk up from the current directory looking for the project root. Returns
+/// `NoProjectRoot` instead of panicking so `main`'s `setup` can route it
+/// through the same error-webview path as a missing/too-old Python.
+fn find_project_root() -> Result<PathBuf, PreflightError> {
+  let Ok(mut dir) = std::env::current_dir() else {
+    return Err(PreflightError::NoProjectRoot);
+  };
   loop {
     if dir.join("package.json").exists() && dir.join("pnpm-lock.yaml").exists() {
-      return dir;
+      return Ok(dir);
     }
     if !dir.pop() {
-      panic!("Could not find project root (package.json not found in any parent)");
+      return Err(PreflightError::NoProjectRoot);
     }
   }
 }
 
-fn spawn_bridge(root: &PathBuf, port: u16) -> Child {
+/// Spawn the bridge on `port` and atomically swap it into `BridgeState`,
+/// returning whatever handle was there before (e.g. a still-dying previous
+/// attempt on a port retry) for the caller to clean up.
+///
+/// The swap happens under a single lock acquisition specifically so
+/// `BridgeState.handle` is never observably `None` while a retry is in
+/// flight — both the supervisor (chunk0-3) and the exit handler (chunk0-1)
+/// treat `None` as "nothing to manage" rather than "respawn in progress",
+/// so a bare take-then-spawn-then-store would race both of them.
+fn spawn_bridge_attempt(
+  app_handle: &AppHandle,
+  root: &PathBuf,
+  python: &PathBuf,
+  port: u16,
+) -> Option<BridgeHandle> {
+  let mut child = spawn_bridge(root, port, python);
+  spawn_log_readers(app_handle, &mut child);
+
+  let state = app_handle.state::<BridgeState>();
+  state.handle.lock().unwrap().replace(BridgeHandle { child, port })
+}
+
+/// Launch the bridge on a candidate port drawn from `port_range()`, and kick
+/// off the readiness probe in the background. If the bridge doesn't come up
+/// (its process exits before answering, or `READY_TIMEOUT` passes), retry on
+/// a newly drawn candidate port, trying at most `MAX_PORT_ATTEMPTS` ports in
+/// all. On success the bridge origin is injected
+/// into the frontend and `ready_event` is emitted; on exhaustion
+/// `bridge-failed` is emitted instead.
+fn launch_bridge(app_handle: &AppHandle, root: &PathBuf, python: &PathBuf, ready_event: &'static str) {
+  let range = port_range();
+  let mut port = pick_candidate_port(range);
+  spawn_bridge_attempt(app_handle, root, python, port);
+
+  let app_handle = app_handle.clone();
+  let root = root.clone();
+  let python = python.clone();
+  std::thread::spawn(move || {
+    for attempt in 1..=MAX_PORT_ATTEMPTS {
+      match probe_bridge(&app_handle, port) {
+        BridgeProbe::Ready => {
+          if let Some(win) = app_handle.get_webview_window("main") {
+            let origin = js_string(&format!("http://127.0.0.1:{port}"));
+            let _ = win.eval(&format!("window.__BRIDGE_ORIGIN__ = {origin};"));
+          }
+          let _ = app_handle.emit(ready_event, port);
+          return;
+        }
+        BridgeProbe::Exited | BridgeProbe::TimedOut if attempt < MAX_PORT_ATTEMPTS => {
+          // On a timeout the previous attempt's child may still be running
+          // (see the contract note on spawn_bridge) — the swap below
+          // hands it back so we can kill it once the next attempt is
+          // already in `BridgeState`, instead of clearing the state first.
+          port = pick_candidate_port(range);
+          if let Some(mut prev) = spawn_bridge_attempt(&app_handle, &root, &python, port) {
+            let _ = prev.child.kill();
+            let _ = prev.child.wait();
+          }
+        }
+        _ => {
+          let _ = app_handle.emit(
+            "bridge-failed",
+            format!("bridge did not become ready after {attempt} attempt(s); last tried port {port}"),
+          );
+          return;
+        }
+      }
+    }
+  });
+}
+
+/// Backoff delay before the `attempt`-th restart (1-based), doubling from
+/// `RESTART_BACKOFF_START` and capped at `RESTART_BACKOFF_CAP`.
+fn restart_backoff(attempt: usize) -> Duration {
+  let shift = (attempt - 1).min(16) as u32;
+  RESTART_BACKOFF_START
+    .checked_mul(1 << shift)
+    .unwrap_or(RESTART_BACKOFF_CAP)
+    .min(RESTART_BACKOFF_CAP)
+}
+
+/// Watch the bridge child in the background and respawn it if it exits
+/// unexpectedly (i.e. not as part of our own shutdown sequence). Restarts
+/// are capped at `MAX_RESTARTS` within `RESTART_WINDOW`, and each one is
+/// preceded by a growing backoff (`restart_backoff`) to avoid a tight
+/// crash loop; once the cap is hit we give up and emit `bridge-fatal`.
+fn spawn_supervisor(app_handle: AppHandle, root: PathBuf, python: PathBuf) {
+  std::thread::spawn(move || {
+    let mut restarts: Vec<Instant> = Vec::new();
+
+    loop {
+      std::thread::sleep(SUPERVISOR_POLL);
+
+      let state = app_handle.state::<BridgeState>();
+      if state.shutting_down.load(Ordering::SeqCst) {
+        return;
+      }
+
+      let exited = {
+        let mut guard = state.handle.lock().unwrap();
+        match guard.as_mut() {
+          Some(handle) => matches!(handle.child.try_wait(), Ok(Some(_))),
+          None => return,
+        }
+      };
+      if !exited {
+        continue;
+      }
+      if state.shutting_down.load(Ordering::SeqCst) {
+        return;
+      }
+
+      let now = Instant::now();
+      restarts.retain(|t| now.duration_since(*t) < RESTART_WINDOW);
+      restarts.push(now);
+      if restarts.len() > MAX_RESTARTS {
+        let _ = app_handle.emit(
+          "bridge-fatal",
+          format!(
+            "bridge crashed {} times within {RESTART_WINDOW:?}; giving up",
+            restarts.len()
+          ),
+        );
+        return;
+      }
+
+      std::thread::sleep(restart_backoff(restarts.len()));
+      if state.shutting_down.load(Ordering::SeqCst) {
+        return;
+      }
+
+      launch_bridge(&app_handle, &root, &python, "bridge-restarted");
+    }
+  });
+}
+
+/// Spawn `bridge.py` bound to `port`.
+///
+/// Contract `bridge.py` is expected to uphold so `probe_bridge`'s retry loop
+/// works: if it can't bind the requested port, exit promptly rather than
+/// logging-and-continuing — a process that lingers on a failed bind just
+/// burns the full `READY_TIMEOUT` as a `TimedOut` instead of an immediate
+/// `Exited`, which still retries (see `BridgeProbe`) but costs ~20s per
+/// attempt instead of near-zero.
+fn spawn_bridge(root: &PathBuf, port: u16, python: &PathBuf) -> Child {
   let script = root.join("src").join("bridge.py");
 
-  let mut cmd = if let Some(py) = venv_python(root) {
-    Command::new(py)
-  } else if cfg!(target_os = "windows") {
-    let mut c = Command::new("py");
-    c.arg("-3");
-    c
-  } else {
-    Command::new("python3")
-  };
+  let mut cmd = Command::new(python);
+  if python.file_stem().and_then(|s| s.to_str()) == Some("py") {
+    cmd.arg("-3");
+  }
 
   println!("Spawning bridge: {:?} --host 127.0.0.1 --port {}", script, port);
 
   cmd.arg(script)
     .args(["--host", "127.0.0.1", "--port", &port.to_string()])
     .current_dir(root.join("src")) // <-- so relative paths in bridge resolve to src/
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
     .spawn()
     .expect("spawn bridge.py")
 }
 
+/// Parse an optional `INFO`/`WARN`/`ERROR` prefix off a bridge log line,
+/// defaulting to `default_level` (stdout -> INFO, stderr -> ERROR) when the
+/// line doesn't start with one.
+fn parse_log_level(line: &str, default_level: &str) -> (String, String) {
+  for level in ["INFO", "WARN", "ERROR"] {
+    if let Some(rest) = line.strip_prefix(level) {
+      if let Some(rest) = rest.strip_prefix(':').or_else(|| rest.strip_prefix(' ')) {
+        return (level.to_string(), rest.trim_start().to_string());
+      }
+    }
+  }
+  (default_level.to_string(), line.to_string())
+}
+
+fn unix_ts() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_secs())
+    .unwrap_or(0)
+}
+
+/// Stream the bridge's stdout/stderr to the frontend as `bridge-log` events,
+/// since piped stdio would otherwise be invisible once the app is packaged.
+fn spawn_log_readers(app_handle: &AppHandle, child: &mut Child) {
+  if let Some(stdout) = child.stdout.take() {
+    let app_handle = app_handle.clone();
+    std::thread::spawn(move || {
+      for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+        let (level, line) = parse_log_level(&line, "INFO");
+        let _ = app_handle.emit(
+          "bridge-log",
+          BridgeLog { level, line, ts: unix_ts() },
+        );
+      }
+    });
+  }
+  if let Some(stderr) = child.stderr.take() {
+    let app_handle = app_handle.clone();
+    std::thread::spawn(move || {
+      for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+        let (level, line) = parse_log_level(&line, "ERROR");
+        let _ = app_handle.emit(
+          "bridge-log",
+          BridgeLog { level, line, ts: unix_ts() },
+        );
+      }
+    });
+  }
+}
+
 fn main() {
   tauri::Builder::default()
-    .manage(BridgeState(Mutex::new(None)))
+    .manage(BridgeState::new())
     .setup(|app| {
-      let port = pick_free_port();
-      let root = find_project_root();
+      // Remote mode: attach to an externally-run bridge instead of
+      // spawning our own local Python process. `BridgeState` stays empty
+      // so the exit handler doesn't try to kill a process it doesn't own.
+      if let Some(url) = bridge_url_override() {
+        return match validate_bridge_url(&url) {
+          Ok(url) => {
+            if let Some(win) = app.get_webview_window("main") {
+              let origin = js_string(&url);
+              let _ = win.eval(&format!("window.__BRIDGE_ORIGIN__ = {origin};"));
+            }
+            let _ = app.handle().emit("bridge-ready", url);
+            Ok(())
+          }
+          Err(msg) => {
+            show_error(app, &msg);
+            Ok(())
+          }
+        };
+      }
 
-      let child = spawn_bridge(&root, port);
-      *app.state::<BridgeState>().0.lock().unwrap() = Some(child);
+      let root = match find_project_root() {
+        Ok(root) => root,
+        Err(err) => {
+          show_preflight_error(app, &err);
+          return Ok(());
+        }
+      };
+
+      let python = match preflight(&root) {
+        Ok(python) => python,
+        Err(err) => {
+          show_preflight_error(app, &err);
+          return Ok(());
+        }
+      };
+
+      // Don't expose the bridge origin until it's actually accepting
+      // connections, otherwise the webview's first requests race the
+      // bridge's socket bind and fail with connection-refused.
+      launch_bridge(app.handle(), &root, &python, "bridge-ready");
+      spawn_supervisor(app.handle().clone(), root, python);
 
-      // Tell frontend where backend is
-      if let Some(win) = app.get_webview_window("main") {
-        win.eval(&format!(
-          "window.__BRIDGE_ORIGIN__ = 'http://127.0.0.1:{port}';"
-        ))?;
-      }
       Ok(())
     })
     .build(tauri::generate_context!())
     .expect("error building tauri app")
     .run(|app_handle, event| {
-      if let RunEvent::ExitRequested { .. } = event {
-        if let Some(mut child) = app_handle.state::<BridgeState>().0.lock().unwrap().take() {
-          let _ = child.kill();
+      if let RunEvent::ExitRequested { api, .. } = event {
+        let state = app_handle.state::<BridgeState>();
+        state.shutting_down.store(true, Ordering::SeqCst);
+        if let Some(handle) = state.handle.lock().unwrap().take() {
+          // shutdown_bridge blocks for up to SHUTDOWN_GRACE; keep that off
+          // the main event-loop thread so the app doesn't freeze on quit.
+          api.prevent_exit();
+          let app_handle = app_handle.clone();
+          std::thread::spawn(move || {
+            shutdown_bridge(handle);
+            app_handle.exit(0);
+          });
         }
       }
     });
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_python_version_parses_major_minor() {
+    assert_eq!(parse_python_version("Python 3.11.4"), Some((3, 11)));
+    assert_eq!(parse_python_version("Python 3.9.0\n"), Some((3, 9)));
+  }
+
+  #[test]
+  fn parse_python_version_distinguishes_3_9_from_3_10() {
+    assert!(parse_python_version("Python 3.9.18").unwrap() < MIN_PYTHON_VERSION);
+    assert!(parse_python_version("Python 3.10.0").unwrap() >= MIN_PYTHON_VERSION);
+  }
+
+  #[test]
+  fn parse_python_version_rejects_malformed_input() {
+    assert_eq!(parse_python_version(""), None);
+    assert_eq!(parse_python_version("not python"), None);
+    assert_eq!(parse_python_version("Python"), None);
+    assert_eq!(parse_python_version("Python 3"), None);
+  }
+
+  #[test]
+  fn parse_port_range_parses_min_max() {
+    assert_eq!(parse_port_range("49152-65535"), Some((49152, 65535)));
+    assert_eq!(parse_port_range(" 1000 - 2000 "), Some((1000, 2000)));
+  }
+
+  #[test]
+  fn parse_port_range_rejects_malformed_or_inverted_input() {
+    assert_eq!(parse_port_range(""), None);
+    assert_eq!(parse_port_range("49152"), None);
+    assert_eq!(parse_port_range("abc-def"), None);
+    assert_eq!(parse_port_range("65535-49152"), None);
+  }
+
+  #[test]
+  fn pick_candidate_port_stays_within_range() {
+    let range = (49152, 49160);
+    for _ in 0..100 {
+      let port = pick_candidate_port(range);
+      assert!(port >= range.0 && port <= range.1, "{port} out of range {range:?}");
+    }
+  }
+
+  #[test]
+  fn pick_candidate_port_handles_single_port_range() {
+    assert_eq!(pick_candidate_port((1000, 1000)), 1000);
+  }
+
+  #[test]
+  fn validate_bridge_url_accepts_http_and_https() {
+    assert_eq!(
+      validate_bridge_url("http://10.0.0.5:9000").unwrap(),
+      "http://10.0.0.5:9000"
+    );
+    assert_eq!(
+      validate_bridge_url("https://bridge.example.com/").unwrap(),
+      "https://bridge.example.com"
+    );
+  }
+
+  #[test]
+  fn validate_bridge_url_rejects_missing_scheme_or_host() {
+    assert!(validate_bridge_url("10.0.0.5:9000").is_err());
+    assert!(validate_bridge_url("ftp://10.0.0.5").is_err());
+    assert!(validate_bridge_url("http://").is_err());
+  }
+
+  #[test]
+  fn js_string_escapes_quotes_and_backslashes() {
+    assert_eq!(js_string("it's a \"test\""), "\"it's a \\\"test\\\"\"");
+    assert_eq!(js_string("back\\slash"), "\"back\\\\slash\"");
+  }
+
+  #[test]
+  fn parse_log_level_strips_known_prefixes() {
+    assert_eq!(
+      parse_log_level("INFO: capture started", "INFO"),
+      ("INFO".to_string(), "capture started".to_string())
+    );
+    assert_eq!(
+      parse_log_level("WARN camera dropped a frame", "INFO"),
+      ("WARN".to_string(), "camera dropped a frame".to_string())
+    );
+    assert_eq!(
+      parse_log_level("ERROR: traceback follows", "INFO"),
+      ("ERROR".to_string(), "traceback follows".to_string())
+    );
+  }
+
+  #[test]
+  fn parse_log_level_falls_back_to_default_for_unrecognized_or_partial_prefixes() {
+    // "WARNING:" isn't one of our known prefixes ("WARN" followed by `:`/` `),
+    // so it falls through untouched rather than being misread as WARN.
+    assert_eq!(
+      parse_log_level("WARNING: disk almost full", "INFO"),
+      ("INFO".to_string(), "WARNING: disk almost full".to_string())
+    );
+    assert_eq!(
+      parse_log_level("plain line with no prefix", "ERROR"),
+      ("ERROR".to_string(), "plain line with no prefix".to_string())
+    );
+  }
+}